@@ -61,6 +61,9 @@ impl TreeState {
         fee_account: AccountId,
     ) -> Self {
         let state = ZkSyncState::from_acc_map(accounts);
+        if let Err(err) = state.validate_integrity() {
+            panic!("Loaded state is inconsistent: {}", err);
+        }
         let last_fee_account_address = state
             .get_account(fee_account)
             .expect("Cant get fee account from tree state")
@@ -101,6 +104,9 @@ impl TreeState {
         });
 
         let state = ZkSyncState::new(balance_tree, account_id_by_address, nfts);
+        if let Err(err) = state.validate_integrity() {
+            panic!("State restored from cache is inconsistent: {}", err);
+        }
         let last_fee_account_address = state
             .get_account(current_block.fee_account)
             .expect("Failed to obtain fee account address from the cached tree")
@@ -148,6 +148,7 @@ pub async fn run_core(
         config.chain.state_keeper.block_chunk_sizes.clone(),
         config.chain.state_keeper.miniblock_iterations as usize,
         config.chain.state_keeper.fast_block_miniblock_iterations as usize,
+        config.chain.state_keeper.events_send_timeout(),
         processed_tx_events_sender,
     );
 
@@ -79,8 +79,13 @@ impl ZkSyncStateInitParams {
         let reverted_blocks =
             Self::load_reverted_blocks(storage, fee_account_id, available_chunk_sizes).await;
 
+        let state = ZkSyncState::new(tree, acc_id_by_addr, nfts);
+        if let Err(err) = state.validate_integrity() {
+            panic!("Restored state is inconsistent: {}", err);
+        }
+
         let init_params = Self {
-            state: ZkSyncState::new(tree, acc_id_by_addr, nfts),
+            state,
             last_block_number,
             unprocessed_priority_op,
             pending_block,
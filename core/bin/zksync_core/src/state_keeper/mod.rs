@@ -7,6 +7,7 @@ use futures::{
     stream::StreamExt,
     SinkExt,
 };
+use tokio::sync::broadcast;
 use tokio::task::JoinHandle;
 use tokio::time;
 // Workspace uses
@@ -21,7 +22,7 @@ use zksync_types::{
     gas_counter::GasCounter,
     mempool::SignedTxVariant,
     tx::ZkSyncTx,
-    Address, PriorityOp, SignedZkSyncTx,
+    Address, BlockNumber, PriorityOp, SignedZkSyncTx,
 };
 // Local uses
 use self::{
@@ -38,7 +39,7 @@ use crate::{
 pub use self::{
     init_params::ZkSyncStateInitParams,
     root_hash_calculator::{start_root_hash_calculator, BlockRootHashJobQueue},
-    types::StateKeeperTestkitRequest,
+    types::{SealedBlockEvent, StateKeeperMemoryStats, StateKeeperTestkitRequest},
 };
 
 mod init_params;
@@ -51,6 +52,10 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
+/// Amount of messages kept for the subscribers of the state keeper and the root hash calculator.
+/// Subscribers that fall behind by more than that will miss the older messages.
+const SUBSCRIPTION_CAPACITY: usize = 64;
+
 /// Responsible for tx processing and block forming.
 pub struct ZkSyncStateKeeper {
     /// Current plasma state
@@ -63,6 +68,11 @@ pub struct ZkSyncStateKeeper {
     /// Channel used for sending queued transaction events. Required since state keeper
     /// has no access to the database.
     processed_tx_events_sender: mpsc::Sender<ProcessedOperations>,
+    /// Set once the events emitter didn't accept processed operations within the grace period.
+    /// Until it catches up, events are sent without waiting, so a stuck emitter doesn't delay every miniblock.
+    events_emitter_lagging: bool,
+    /// Broadcast channel notifying subscribers about sealed blocks.
+    sealed_blocks_sender: broadcast::Sender<SealedBlockEvent>,
 
     /// Queue for root hash calculator.
     /// Contains blocks that were sealed but for which root hash has not been calculated yet.
@@ -86,6 +96,7 @@ impl ZkSyncStateKeeper {
         available_block_chunk_sizes: Vec<usize>,
         max_miniblock_iterations: usize,
         fast_miniblock_iterations: usize,
+        events_send_timeout: Duration,
         processed_tx_events_sender: mpsc::Sender<ProcessedOperations>,
     ) -> (Self, RootHashCalculator) {
         // We need two copies of state:
@@ -134,6 +145,7 @@ impl ZkSyncStateKeeper {
             available_block_chunk_sizes,
             max_miniblock_iterations,
             fast_miniblock_iterations,
+            events_send_timeout,
         );
 
         let pending_block = {
@@ -156,6 +168,8 @@ impl ZkSyncStateKeeper {
             tx_for_commitments,
             tx_for_mempool,
             processed_tx_events_sender,
+            events_emitter_lagging: false,
+            sealed_blocks_sender: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
 
             root_hash_queue,
             reverted_blocks: initial_state.reverted_blocks.clone(),
@@ -244,6 +258,15 @@ impl ZkSyncStateKeeper {
         metrics::histogram!("state_keeper.initialize", start.elapsed());
     }
 
+    /// Creates a new subscription to the sealed blocks.
+    ///
+    /// Every subscriber gets its own receiver, and the state keeper never waits for them:
+    /// a subscriber that doesn't keep up will get a `RecvError::Lagged` error and skip the
+    /// oldest events.
+    pub fn subscribe(&self) -> broadcast::Receiver<SealedBlockEvent> {
+        self.sealed_blocks_sender.subscribe()
+    }
+
     pub async fn execute_reverted_blocks(&mut self) {
         while let Some(block) = self.reverted_blocks.pop_front() {
             self.execute_incomplete_block(block).await;
@@ -297,6 +320,7 @@ impl ZkSyncStateKeeper {
             metrics::histogram!("miniblock_size", proposed_block.size() as f64);
 
             self.execute_proposed_block(proposed_block).await;
+            self.report_memory_stats();
         }
     }
 
@@ -460,13 +484,11 @@ impl ZkSyncStateKeeper {
         // TODO (ZKS-821): We can store events in `committer` (as it's already responsible for applying results of
         // the block execution), there is no need in additional actor for that.
         if !executed_ops.is_empty() {
-            let _ = self
-                .processed_tx_events_sender
-                .send(ProcessedOperations {
-                    block_number: self.pending_block.number,
-                    executed_ops,
-                })
-                .await;
+            self.send_processed_operations(ProcessedOperations {
+                block_number: self.pending_block.number,
+                executed_ops,
+            })
+            .await;
         }
 
         // Iteration is complete, increment it in the pending block.
@@ -496,6 +518,66 @@ impl ZkSyncStateKeeper {
         metrics::histogram!("state_keeper.execute_proposed_block", start.elapsed());
     }
 
+    /// Sends processed operations to the events emitter.
+    ///
+    /// Events are not required for the block processing, so if the emitter is gone we just report it.
+    /// If the channel is full, we wait for the configured grace period before giving up, so that a
+    /// temporarily slow emitter does not lose events. Once the grace period has expired, we stop waiting
+    /// and only send events the channel can accept right away, until the emitter catches up.
+    async fn send_processed_operations(&mut self, operations: ProcessedOperations) {
+        let block_number = operations.block_number;
+
+        if self.events_emitter_lagging {
+            match self.processed_tx_events_sender.try_send(operations) {
+                Ok(()) => {
+                    vlog::info!("Events emitter caught up, resuming waiting for it");
+                    self.events_emitter_lagging = false;
+                }
+                Err(err) if err.is_full() => {
+                    vlog::warn!(
+                        "Events emitter is still lagging, dropping processed operations for block {}",
+                        block_number
+                    );
+                    metrics::increment_counter!("state_keeper.dropped_processed_operations");
+                }
+                Err(err) => {
+                    vlog::warn!(
+                        "Unable to send processed operations for block {}: {}",
+                        block_number,
+                        err
+                    );
+                }
+            }
+            return;
+        }
+
+        let send_result = time::timeout(
+            self.config.events_send_timeout,
+            self.processed_tx_events_sender.send(operations),
+        )
+        .await;
+
+        match send_result {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => {
+                vlog::warn!(
+                    "Unable to send processed operations for block {}: {}",
+                    block_number,
+                    err
+                );
+            }
+            Err(_) => {
+                vlog::error!(
+                    "Events emitter did not accept processed operations for block {} in {:?}, dropping them",
+                    block_number,
+                    self.config.events_send_timeout
+                );
+                metrics::increment_counter!("state_keeper.dropped_processed_operations");
+                self.events_emitter_lagging = true;
+            }
+        }
+    }
+
     // Err if there is no space in current block
     fn apply_priority_op(&mut self, priority_op: &PriorityOp) -> ApplyOutcome<ExecutedOperations> {
         let start = Instant::now();
@@ -780,8 +862,13 @@ impl ZkSyncStateKeeper {
         // should be spearated.
         let current_block = self.pending_block.number;
         let next_unprocessed_priority_op = self.pending_block.unprocessed_priority_op_current;
+        // Contract stores block numbers as `uint32`, so wrapping around would make block numbers collide.
+        let next_block_number = current_block
+            .checked_add(1)
+            .map(BlockNumber)
+            .expect("Block number overflow: no more blocks can be created");
         let new_pending_block = PendingBlock::new(
-            self.pending_block.number + 1,
+            next_block_number,
             next_unprocessed_priority_op,
             self.config.max_block_size(),
             system_time_timestamp(),
@@ -800,7 +887,7 @@ impl ZkSyncStateKeeper {
         let verify_gas_limit = self.pending_block.gas_counter.verify_gas_limit();
 
         let block = IncompleteBlock::new_from_available_block_sizes(
-            self.pending_block.number,
+            current_block,
             self.config.fee_account_id,
             block_transactions,
             (
@@ -841,6 +928,12 @@ impl ZkSyncStateKeeper {
             block: current_block,
             updates: self.pending_block.account_updates.clone(),
         };
+        // Committer and root hash calculator receive two separate views of the same block,
+        // and the committer matches them by block number once the root hash is calculated.
+        debug_assert_eq!(
+            root_hash_job.block, block_commit_request.block.block_number,
+            "Sealed block and its root hash job refer to different blocks"
+        );
 
         vlog::info!(
             "Creating full block: {}, operations: {}, chunks_left: {}, miniblock iterations: {}",
@@ -855,6 +948,12 @@ impl ZkSyncStateKeeper {
             "stage" => "state_keeper"
         );
 
+        let sealed_block_event = SealedBlockEvent {
+            block_number: block_commit_request.block.block_number,
+            operations_count: block_commit_request.block.block_transactions.len(),
+            timestamp: block_commit_request.block.timestamp,
+        };
+
         let commit_request =
             CommitRequest::SealIncompleteBlock((block_commit_request, applied_updates_request));
         self.pending_block = new_pending_block;
@@ -863,6 +962,8 @@ impl ZkSyncStateKeeper {
             .await
             .expect("committer receiver dropped");
         self.root_hash_queue.push(root_hash_job).await;
+        // Sending only fails if there are no subscribers, which is fine.
+        let _ = self.sealed_blocks_sender.send(sealed_block_event);
         metrics::histogram!("state_keeper.seal_pending_block", start.elapsed());
     }
 
@@ -902,6 +1003,29 @@ impl ZkSyncStateKeeper {
             reverted_blocks: self.reverted_blocks.clone(),
         }
     }
+
+    /// Returns the approximate memory footprint of the state keeper.
+    pub fn memory_stats(&self) -> StateKeeperMemoryStats {
+        StateKeeperMemoryStats {
+            tree: self.state.tree_memory_stats(),
+            root_hash_jobs: self.root_hash_queue.size(),
+            reverted_blocks: self.reverted_blocks.len(),
+        }
+    }
+
+    /// Reports the state keeper queues as metrics.
+    /// The tree isn't reported here, since the root hash calculator reports its copy of it.
+    fn report_memory_stats(&self) {
+        let memory_stats = self.memory_stats();
+        metrics::gauge!(
+            "state_keeper.root_hash_jobs",
+            memory_stats.root_hash_jobs as f64
+        );
+        metrics::gauge!(
+            "state_keeper.reverted_blocks",
+            memory_stats.reverted_blocks as f64
+        );
+    }
 }
 
 #[must_use]
@@ -1,11 +1,12 @@
 use std::time::Instant;
 
 use futures::{channel::mpsc, SinkExt};
-use tokio::task::JoinHandle;
+use tokio::{sync::broadcast, task::JoinHandle};
 
 use zksync_state::state::ZkSyncState;
 use zksync_types::BlockNumber;
 
+use super::SUBSCRIPTION_CAPACITY;
 use crate::committer::{BlockFinishRequest, CommitRequest};
 
 mod queue;
@@ -31,6 +32,8 @@ pub struct RootHashCalculator {
     // so we can throttle performance if needed.
     job_queue: BlockRootHashJobQueue,
     tx_for_commitments: mpsc::Sender<CommitRequest>,
+    /// Publishes every calculated root hash along with its block number.
+    root_hashes_sender: broadcast::Sender<BlockFinishRequest>,
 
     // While we don't really need the number for calculations, it's useful for safety
     // to ensure that every block is processed in order.
@@ -51,10 +54,19 @@ impl RootHashCalculator {
             state,
             job_queue,
             tx_for_commitments,
+            root_hashes_sender: broadcast::channel(SUBSCRIPTION_CAPACITY).0,
             last_block_number,
         }
     }
 
+    /// Returns a receiver of the calculated root hashes, delivered in block order.
+    ///
+    /// A root hash is published right after it's sent to the committer, so a received
+    /// block may still not be finalized in the database.
+    pub fn subscribe(&self) -> broadcast::Receiver<BlockFinishRequest> {
+        self.root_hashes_sender.subscribe()
+    }
+
     pub async fn run(mut self) {
         loop {
             let job = self.job_queue.pop().await;
@@ -80,14 +92,16 @@ impl RootHashCalculator {
 
         vlog::info!("Root hash for block #{} is calculated", job.block);
 
-        let finalize_request = CommitRequest::FinishBlock(BlockFinishRequest {
+        let finish_request = BlockFinishRequest {
             block_number: job.block,
             root_hash,
-        });
+        };
         self.tx_for_commitments
-            .send(finalize_request)
+            .send(CommitRequest::FinishBlock(finish_request.clone()))
             .await
             .expect("committer receiver dropped");
+        // Nobody may be subscribed yet, the root hash is still delivered to the committer.
+        let _ = self.root_hashes_sender.send(finish_request);
 
         // Increment block number to expect the next one.
         self.last_block_number = self.last_block_number + 1;
@@ -1,4 +1,7 @@
+use std::time::{Duration, Instant};
+
 use chrono::Utc;
+use futures::{channel::mpsc, StreamExt};
 use zksync_mempool::ProposedBlock;
 use zksync_types::{
     mempool::SignedTxVariant, AccountId, BlockNumber, Nonce, SignedZkSyncTx, TokenId, Transfer,
@@ -6,6 +9,7 @@ use zksync_types::{
 };
 
 use super::utils::*;
+use crate::tx_event_emitter::ProcessedOperations;
 
 /// Checks if executing a proposed_block with just enough chunks is done correctly
 /// and checks if number of chunks left is correct after each operation.
@@ -565,3 +569,96 @@ async fn transfers_with_different_timestamps() {
         })
         .await;
 }
+
+/// Creates a channel for processed operations events which is already full,
+/// so the state keeper will have to wait until its receiver gets polled.
+fn full_events_channel() -> (
+    mpsc::Sender<ProcessedOperations>,
+    mpsc::Receiver<ProcessedOperations>,
+) {
+    let (mut events_sender, events_receiver) = mpsc::channel(0);
+    events_sender
+        .try_send(ProcessedOperations {
+            block_number: BlockNumber(0),
+            executed_ops: Vec::new(),
+        })
+        .expect("Channel has a slot for each sender");
+    (events_sender, events_receiver)
+}
+
+/// Checks that processed operations are delivered to the events emitter
+/// if it becomes available within the grace period.
+#[tokio::test]
+async fn processed_operations_delivered_to_slow_emitter() {
+    let mut tester = StateKeeperTester::new(20, 5, 5);
+    let (events_sender, mut events_receiver) = full_events_channel();
+    tester.state_keeper.processed_tx_events_sender = events_sender;
+
+    let emitter = tokio::spawn(async move {
+        // Emitter is busy for some time, but it's less than the grace period.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let first = events_receiver.next().await.unwrap();
+        let second = events_receiver.next().await.unwrap();
+        (first, second)
+    });
+
+    apply_single_transfer(&mut tester).await;
+    tester.assert_pending().await;
+
+    let (first, second) = emitter.await.expect("Emitter task failed");
+    assert_eq!(first.block_number, BlockNumber(0));
+    assert_eq!(second.block_number, BlockNumber(1));
+    assert_eq!(second.executed_ops.len(), 1);
+}
+
+/// Checks that the state keeper doesn't get stuck if the events emitter doesn't accept
+/// processed operations within the grace period.
+#[tokio::test]
+async fn processed_operations_dropped_after_grace_period() {
+    let mut tester = StateKeeperTester::new(20, 5, 5);
+    let (events_sender, _events_receiver) = full_events_channel();
+    tester.state_keeper.processed_tx_events_sender = events_sender;
+    tester.state_keeper.config.events_send_timeout = Duration::from_millis(50);
+
+    tokio::time::timeout(Duration::from_secs(1), apply_single_transfer(&mut tester))
+        .await
+        .expect("State keeper got stuck on the events emitter");
+    tester.assert_pending().await;
+}
+
+/// Checks that once the grace period has expired, the state keeper doesn't wait for the stuck
+/// events emitter on every following miniblock, and resumes sending events once it catches up.
+#[tokio::test]
+async fn processed_operations_not_delayed_while_emitter_lags() {
+    let events_send_timeout = Duration::from_millis(200);
+    let mut tester = StateKeeperTester::new(20, 5, 5);
+    let (events_sender, mut events_receiver) = full_events_channel();
+    tester.state_keeper.processed_tx_events_sender = events_sender;
+    tester.state_keeper.config.events_send_timeout = events_send_timeout;
+
+    // The first miniblock waits for the whole grace period.
+    apply_single_transfer(&mut tester).await;
+    tester.assert_pending().await;
+
+    // The second one doesn't wait for the emitter at all.
+    let start = Instant::now();
+    apply_single_transfer(&mut tester).await;
+    assert!(
+        start.elapsed() < events_send_timeout,
+        "State keeper waited for the lagging events emitter again"
+    );
+    tester.assert_pending().await;
+
+    // Once the emitter catches up, events are delivered again.
+    let stale = events_receiver.next().await.unwrap();
+    assert_eq!(stale.block_number, BlockNumber(0));
+    apply_single_transfer(&mut tester).await;
+    tester.assert_pending().await;
+
+    let delivered = tokio::time::timeout(Duration::from_secs(1), events_receiver.next())
+        .await
+        .expect("Events were not delivered after the emitter caught up")
+        .unwrap();
+    assert_eq!(delivered.block_number, BlockNumber(1));
+    assert_eq!(delivered.executed_ops.len(), 1);
+}
@@ -1,5 +1,6 @@
 use super::{ZkSyncStateInitParams, ZkSyncStateKeeper};
 use futures::channel::mpsc;
+use std::time::Duration;
 use zksync_types::{AccountId, H160, *};
 
 mod apply_priority_op;
@@ -16,6 +17,7 @@ fn test_create_incorrect_state_keeper() {
     const CHANNEL_SIZE: usize = 32768;
     const MAX_ITERATIONS: usize = 100;
     const FAST_ITERATIONS: usize = 100;
+    const EVENTS_SEND_TIMEOUT: Duration = Duration::from_secs(5);
 
     let (events_sender, _events_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (request_tx, _request_rx) = mpsc::channel(CHANNEL_SIZE);
@@ -37,6 +39,7 @@ fn test_create_incorrect_state_keeper() {
         vec![1, 2, 2], // `available_block_chunk_sizes` must be strictly increasing.
         MAX_ITERATIONS,
         FAST_ITERATIONS,
+        EVENTS_SEND_TIMEOUT,
         events_sender,
     );
 }
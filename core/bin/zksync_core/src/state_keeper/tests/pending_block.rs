@@ -1,7 +1,7 @@
 use chrono::Utc;
 use num::BigUint;
 use zksync_types::tx::TimeRange;
-use zksync_types::{AccountId, SignedZkSyncTx, TokenId, Transfer, ZkSyncTx};
+use zksync_types::{AccountId, BlockNumber, SignedZkSyncTx, TokenId, Transfer, ZkSyncTx};
 
 use super::utils::*;
 use crate::state_keeper::utils::system_time_timestamp;
@@ -254,3 +254,108 @@ async fn correctly_restore_pending_block_timestamp() {
         "Tx with the same valid_until as for previous transaction should've been processed"
     );
 }
+
+/// Checks that the committer and the root hash calculator receive the same block
+/// when the pending block is sealed.
+#[tokio::test]
+async fn sealed_block_matches_root_hash_job() {
+    let mut tester = StateKeeperTester::new(20, 3, 3);
+    let withdraw = create_account_and_withdrawal(
+        &mut tester,
+        TokenId(0),
+        AccountId(1),
+        200u32,
+        145u32,
+        Default::default(),
+    );
+    assert!(tester.state_keeper.apply_tx(&withdraw).is_included());
+    tester.state_keeper.seal_pending_block().await;
+
+    let (block, updates) = tester.unwrap_sealed_update().await;
+    let root_hash_job = tester.state_keeper.root_hash_queue.pop().await;
+
+    assert_eq!(root_hash_job.block, block.block.block_number);
+    assert_eq!(
+        root_hash_job.updates.len(),
+        updates.first_update_order_id + updates.account_updates.len()
+    );
+}
+
+/// Checks that every subscriber is notified about sealed blocks.
+#[tokio::test]
+async fn sealed_block_subscribers() {
+    let mut tester = StateKeeperTester::new(20, 3, 3);
+    let subscribers = vec![
+        tester.state_keeper.subscribe(),
+        tester.state_keeper.subscribe(),
+    ];
+
+    for _ in 0..2 {
+        apply_single_transfer(&mut tester).await;
+        tester.state_keeper.seal_pending_block().await;
+    }
+
+    check_subscribers(subscribers, 2, |position, event| {
+        assert_eq!(event.block_number, BlockNumber(position as u32 + 1));
+        assert_eq!(event.operations_count, 1);
+    })
+    .await;
+}
+
+/// Checks that every subscriber gets the root hash of each block in the order of blocks.
+#[tokio::test]
+async fn root_hash_subscribers() {
+    let mut tester = StateKeeperTester::new(20, 3, 3);
+    let subscribers = vec![
+        tester.root_hash_calculator.subscribe(),
+        tester.root_hash_calculator.subscribe(),
+    ];
+
+    // Deposits create the accounts through the block updates only, so the state keeper state
+    // matches the one the root hash calculator gets by applying these updates.
+    let mut expected_root_hashes = Vec::new();
+    for _ in 0..2 {
+        apply_single_deposit(&mut tester).await;
+        tester.state_keeper.seal_pending_block().await;
+        expected_root_hashes.push(tester.state_keeper.state.root_hash());
+    }
+    assert_ne!(expected_root_hashes[0], expected_root_hashes[1]);
+    tokio::spawn(tester.root_hash_calculator.run());
+
+    check_subscribers(subscribers, 2, |position, finish_request| {
+        assert_eq!(
+            finish_request.block_number,
+            BlockNumber(position as u32 + 1)
+        );
+        assert_eq!(finish_request.root_hash, expected_root_hashes[position]);
+    })
+    .await;
+}
+
+/// Checks that memory stats account for the sealed blocks waiting for the root hash.
+#[tokio::test]
+async fn memory_stats_track_root_hash_jobs() {
+    let mut tester = StateKeeperTester::new(20, 3, 3);
+    assert_eq!(tester.state_keeper.memory_stats().root_hash_jobs, 0);
+
+    for _ in 0..2 {
+        apply_single_transfer(&mut tester).await;
+        tester.state_keeper.seal_pending_block().await;
+    }
+    let stats = tester.state_keeper.memory_stats();
+    assert_eq!(stats.root_hash_jobs, 2);
+    assert!(stats.tree.items > 0);
+
+    tester.state_keeper.root_hash_queue.pop().await;
+    assert_eq!(tester.state_keeper.memory_stats().root_hash_jobs, 1);
+}
+
+/// Checks that the state keeper refuses to create a block with a number that doesn't fit into `u32`.
+#[tokio::test]
+#[should_panic(expected = "Block number overflow")]
+async fn block_number_overflow() {
+    let mut tester = StateKeeperTester::new(20, 3, 3);
+    tester.state_keeper.pending_block.number = BlockNumber(u32::MAX);
+    apply_single_transfer(&mut tester).await;
+    tester.state_keeper.seal_pending_block().await;
+}
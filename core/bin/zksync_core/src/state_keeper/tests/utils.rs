@@ -1,8 +1,13 @@
+use std::time::Duration;
+
 use crate::committer::{AppliedUpdatesRequest, BlockCommitRequest};
-use crate::state_keeper::{CommitRequest, ZkSyncStateInitParams, ZkSyncStateKeeper};
+use crate::state_keeper::{
+    CommitRequest, RootHashCalculator, ZkSyncStateInitParams, ZkSyncStateKeeper,
+};
 use chrono::Utc;
 use futures::{channel::mpsc, stream::StreamExt};
 use num::BigUint;
+use tokio::sync::broadcast;
 use zksync_crypto::{
     priv_key_from_fs,
     rand::{Rng, SeedableRng, XorShiftRng},
@@ -17,6 +22,7 @@ use zksync_types::{
 
 pub struct StateKeeperTester {
     pub state_keeper: ZkSyncStateKeeper,
+    pub root_hash_calculator: RootHashCalculator,
     pub response_rx: mpsc::Receiver<CommitRequest>,
     pub fee_collector: AccountId,
 }
@@ -24,6 +30,7 @@ pub struct StateKeeperTester {
 impl StateKeeperTester {
     pub fn new(available_chunk_size: usize, max_iterations: usize, fast_iterations: usize) -> Self {
         const CHANNEL_SIZE: usize = 32768;
+        const EVENTS_SEND_TIMEOUT: Duration = Duration::from_secs(5);
         let (events_sender, _events_receiver) = mpsc::channel(CHANNEL_SIZE);
         let (request_tx, _request_rx) = mpsc::channel(CHANNEL_SIZE);
         let (response_tx, response_rx) = mpsc::channel(CHANNEL_SIZE);
@@ -35,7 +42,7 @@ impl StateKeeperTester {
             .state
             .insert_account(AccountId(0), fee_collector.clone());
 
-        let (state_keeper, _root_hash_calculator) = ZkSyncStateKeeper::new(
+        let (state_keeper, root_hash_calculator) = ZkSyncStateKeeper::new(
             init_params,
             fee_collector.address,
             response_tx,
@@ -43,11 +50,13 @@ impl StateKeeperTester {
             vec![available_chunk_size],
             max_iterations,
             fast_iterations,
+            EVENTS_SEND_TIMEOUT,
             events_sender,
         );
 
         Self {
             state_keeper,
+            root_hash_calculator,
             response_rx,
             fee_collector: AccountId(0),
         }
@@ -307,6 +316,34 @@ pub fn create_deposit(token: TokenId, amount: impl Into<BigUint>) -> PriorityOp
     }
 }
 
+/// Receives `messages_count` messages from each of the subscribers and passes every message
+/// along with its position to `check`, so all the subscribers are checked against the same expectations.
+pub async fn check_subscribers<T: Clone>(
+    subscribers: Vec<broadcast::Receiver<T>>,
+    messages_count: usize,
+    check: impl Fn(usize, T),
+) {
+    for mut subscriber in subscribers {
+        for position in 0..messages_count {
+            let message = subscriber.recv().await.unwrap_or_else(|err| {
+                panic!("No message #{} for the subscriber: {}", position, err)
+            });
+            check(position, message);
+        }
+    }
+}
+
+pub async fn apply_single_deposit(tester: &mut StateKeeperTester) {
+    let proposed_block = ProposedBlock {
+        txs: Vec::new(),
+        priority_ops: vec![create_deposit(TokenId(0), 100u32)],
+    };
+    tester
+        .state_keeper
+        .execute_proposed_block(proposed_block)
+        .await;
+}
+
 pub async fn apply_single_transfer(tester: &mut StateKeeperTester) {
     let transfer = create_account_and_transfer(tester, TokenId(0), AccountId(1), 200u32, 100u32);
     let proposed_block = ProposedBlock {
@@ -1,9 +1,12 @@
+// Built-in uses
+use std::time::Duration;
 // External uses
 use futures::channel::oneshot;
 use itertools::Itertools;
 // Workspace uses
+use zksync_crypto::merkle_tree::TreeMemoryUsage;
 use zksync_mempool::ProposedBlock;
-use zksync_types::{Account, AccountId, Address};
+use zksync_types::{Account, AccountId, Address, BlockNumber};
 // Local uses
 use crate::state_keeper::init_params::ZkSyncStateInitParams;
 
@@ -17,6 +20,34 @@ pub enum StateKeeperTestkitRequest {
     GetCurrentState(oneshot::Sender<ZkSyncStateInitParams>),
 }
 
+/// Approximate memory footprint of the state keeper.
+///
+/// The state keeper doesn't have caches that could grow without bounds, so there is nothing to evict:
+/// the tree holds the actual state, the root hash jobs queue is bounded by throttling, and reverted
+/// blocks are only loaded on startup and drained before the state keeper starts processing new blocks.
+#[derive(Debug, Clone, Copy)]
+pub struct StateKeeperMemoryStats {
+    /// Memory used by the account tree.
+    pub tree: TreeMemoryUsage,
+    /// Amount of sealed blocks waiting for the root hash to be calculated.
+    /// This queue is bounded by the state keeper throttling.
+    pub root_hash_jobs: usize,
+    /// Amount of reverted blocks that are not re-executed yet.
+    pub reverted_blocks: usize,
+}
+
+/// Notification about a block sealed by the state keeper.
+///
+/// Root hash is not known at this point, since it's calculated by the root hash calculator
+/// after the block is sealed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SealedBlockEvent {
+    pub block_number: BlockNumber,
+    /// Amount of the operations (both successful and failed) included into the block.
+    pub operations_count: usize,
+    pub timestamp: u64,
+}
+
 #[derive(Debug)]
 pub enum ApplyOutcome<T> {
     Included(T),
@@ -48,6 +79,9 @@ pub(super) struct StateKeeperConfig {
     pub(super) available_block_chunk_sizes: Vec<usize>,
     pub(super) max_miniblock_iterations: usize,
     pub(super) fast_miniblock_iterations: usize,
+    /// Grace period for the events emitter channel: if it's full for longer than that,
+    /// processed operations events are dropped so that block processing isn't stalled.
+    pub(super) events_send_timeout: Duration,
     max_block_size: usize,
 }
 
@@ -57,6 +91,7 @@ impl StateKeeperConfig {
         available_block_chunk_sizes: Vec<usize>,
         max_miniblock_iterations: usize,
         fast_miniblock_iterations: usize,
+        events_send_timeout: Duration,
     ) -> Self {
         // Ensure that available block chunk sizes are sorted and not empty.
         assert!(
@@ -81,6 +116,7 @@ impl StateKeeperConfig {
             available_block_chunk_sizes,
             max_miniblock_iterations,
             fast_miniblock_iterations,
+            events_send_timeout,
             max_block_size,
         }
     }
@@ -94,10 +130,13 @@ impl StateKeeperConfig {
 mod tests {
     use super::*;
 
+    const EVENTS_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Checks that config can be created if provided values are correct.
     #[test]
     fn create_config() {
-        let config = StateKeeperConfig::new(AccountId(0), vec![1, 2, 3], 10, 20);
+        let config =
+            StateKeeperConfig::new(AccountId(0), vec![1, 2, 3], 10, 20, EVENTS_SEND_TIMEOUT);
         assert_eq!(config.max_block_size, 3);
     }
 
@@ -106,7 +145,8 @@ mod tests {
     #[should_panic(expected = "Block chunk sizes are not in order")]
     fn config_chunks_out_of_order() {
         let incorrect_chunks = vec![3, 1, 2];
-        let _config = StateKeeperConfig::new(AccountId(0), incorrect_chunks, 10, 20);
+        let _config =
+            StateKeeperConfig::new(AccountId(0), incorrect_chunks, 10, 20, EVENTS_SEND_TIMEOUT);
     }
 
     /// Checks that if chunk sizes are empty, it will panic.
@@ -114,6 +154,7 @@ mod tests {
     #[should_panic(expected = "Block chunk sizes are empty")]
     fn config_chunks_empty() {
         let incorrect_chunks = vec![];
-        let _config = StateKeeperConfig::new(AccountId(0), incorrect_chunks, 10, 20);
+        let _config =
+            StateKeeperConfig::new(AccountId(0), incorrect_chunks, 10, 20, EVENTS_SEND_TIMEOUT);
     }
 }
@@ -86,6 +86,9 @@ pub struct StateKeeper {
     pub miniblock_iterations: u64,
     /// Maximum amount of miniblock iterations in case of block containing a fast withdrawal request.
     pub fast_block_miniblock_iterations: u64,
+    /// Time (in milliseconds) the state keeper waits for the events emitter to accept processed operations
+    /// before dropping them.
+    pub events_send_timeout: u64,
     pub fee_account_addr: Address,
     pub aggregated_proof_sizes: Vec<usize>,
     pub max_aggregated_blocks_to_commit: usize,
@@ -102,6 +105,11 @@ impl StateKeeper {
         Duration::from_millis(self.miniblock_iteration_interval)
     }
 
+    /// Converts `self.events_send_timeout` into `Duration`.
+    pub fn events_send_timeout(&self) -> Duration {
+        Duration::from_millis(self.events_send_timeout)
+    }
+
     pub fn block_commit_deadline(&self) -> Duration {
         Duration::from_secs(self.block_commit_deadline)
     }
@@ -139,6 +147,7 @@ mod tests {
                 miniblock_iteration_interval: 200,
                 miniblock_iterations: 10,
                 fast_block_miniblock_iterations: 5,
+                events_send_timeout: 5_000,
                 fee_account_addr: addr("de03a0B5963f75f1C8485B355fF6D30f3093BDE7"),
                 aggregated_proof_sizes: vec![1, 5],
                 max_aggregated_blocks_to_commit: 3,
@@ -167,6 +176,7 @@ CHAIN_STATE_KEEPER_BLOCK_CHUNK_SIZES="6,30"
 CHAIN_STATE_KEEPER_MINIBLOCK_ITERATION_INTERVAL="200"
 CHAIN_STATE_KEEPER_MINIBLOCK_ITERATIONS="10"
 CHAIN_STATE_KEEPER_FAST_BLOCK_MINIBLOCK_ITERATIONS="5"
+CHAIN_STATE_KEEPER_EVENTS_SEND_TIMEOUT="5000"
 CHAIN_STATE_KEEPER_FEE_ACCOUNT_ADDR="0xde03a0B5963f75f1C8485B355fF6D30f3093BDE7"
 CHAIN_STATE_KEEPER_AGGREGATED_PROOF_SIZES="1,5"
 CHAIN_STATE_KEEPER_MAX_AGGREGATED_BLOCKS_TO_COMMIT="3"
@@ -191,5 +201,9 @@ CHAIN_STATE_KEEPER_MAX_AGGREGATED_TX_GAS="4000000"
             config.state_keeper.miniblock_iteration_interval(),
             Duration::from_millis(config.state_keeper.miniblock_iteration_interval)
         );
+        assert_eq!(
+            config.state_keeper.events_send_timeout(),
+            Duration::from_millis(config.state_keeper.events_send_timeout)
+        );
     }
 }
@@ -188,14 +188,7 @@ where
             right: None,
         }];
 
-        let mut prehashed = Vec::with_capacity(tree_depth);
-        let mut cur = hasher.hash_bits(T::default().get_bits_le());
-        prehashed.push(cur.clone());
-        for i in 0..tree_depth {
-            cur = hasher.compress(&cur, &cur, i);
-            prehashed.push(cur.clone());
-        }
-        prehashed.reverse();
+        let prehashed = Self::default_hashes(&hasher, tree_depth);
 
         let cache = RwLock::new(FnvHashMap::default());
 
@@ -210,6 +203,29 @@ where
         }
     }
 
+    /// Returns the root hash of the tree of certain depth that has no items.
+    ///
+    /// The hash is calculated on every call, so callers that need it often should store it.
+    pub fn empty_root(tree_depth: Depth) -> Hash {
+        Self::default_hashes(&H::default(), tree_depth)
+            .into_iter()
+            .next()
+            .expect("Default hashes always contain the root")
+    }
+
+    /// Calculates the hashes for the "default" nodes at every depth, starting from the root.
+    fn default_hashes(hasher: &H, tree_depth: Depth) -> Vec<Hash> {
+        let mut prehashed = Vec::with_capacity(tree_depth + 1);
+        let mut cur = hasher.hash_bits(T::default().get_bits_le());
+        prehashed.push(cur.clone());
+        for i in 0..tree_depth {
+            cur = hasher.compress(&cur, &cur, i);
+            prehashed.push(cur.clone());
+        }
+        prehashed.reverse();
+        prehashed
+    }
+
     /// Roughly calculates the data on the RAM usage for this tree object.
     /// See the [`TreeMemoryUsage`] doc-comments for details.
     pub fn memory_stats(&self) -> TreeMemoryUsage {
@@ -271,6 +287,20 @@ where
         self.items.get(&index)
     }
 
+    /// Iterates over the stored elements in the ascending order of their indices.
+    ///
+    /// Unlike iterating over `items` directly, the order doesn't depend on the insertion history,
+    /// so it should be used whenever the iteration order affects the result.
+    pub fn iter_ordered(&self) -> impl Iterator<Item = (u32, &T)> {
+        let mut items: Vec<_> = self
+            .items
+            .iter()
+            .map(|(index, item)| (*index as u32, item))
+            .collect();
+        items.sort_unstable_by_key(|(index, _)| *index);
+        items.into_iter()
+    }
+
     /// Inserts an element to the tree.
     pub fn insert(&mut self, item_index: u32, item: T) {
         let item_index = item_index as ItemIndex;
@@ -493,6 +523,26 @@ where
         proof
     }
 
+    /// Returns the hash of the internal node at depth `prefix_depth` covering the leaves
+    /// `[prefix_index << (tree_depth - prefix_depth), (prefix_index + 1) << (tree_depth - prefix_depth))`.
+    ///
+    /// Depth 0 corresponds to the root, and depth `tree_depth` corresponds to a single leaf.
+    pub fn subtree_root(&self, prefix_index: u32, prefix_depth: u32) -> Hash {
+        let depth = prefix_depth as Depth;
+        assert!(depth <= self.tree_depth);
+        assert!((prefix_index as u64) < (1 << depth));
+
+        // Same as for `merkle_path`, calculating the root hash fills the cache,
+        // so every node that is absent in it has the precomputed hash for its depth.
+        let _root_hash = self.root_hash();
+
+        let node_index = NodeIndex((1 << depth) + prefix_index as u64);
+        match self.cache.read().expect("Read lock").get(&node_index) {
+            Some(hash) => hash.clone(),
+            None => self.prehashed[depth].clone(),
+        }
+    }
+
     /// A helper method for `merkle_path`: obtains the hash for the node with provided index,
     /// assuming that it is already calculated. That is, if the node is absent in the cache,
     /// it is assumed that it has the precomputed hash for the current depth.
@@ -942,4 +992,82 @@ mod tests {
             assert_eq!(aggregated_hash, 793_215_819);
         }
     }
+
+    /// Checks that combining the adjacent subtree roots up the tree reproduces the root hash.
+    #[test]
+    fn subtree_root_test() {
+        let mut tree = TestSMT::new(4);
+        for &(idx, value) in &[(0, 2), (3, 2), (9, 5), (15, 1)] {
+            tree.insert(idx, TestLeaf(value));
+        }
+        let root_hash = tree.root_hash();
+        assert_eq!(tree.subtree_root(0, 0), root_hash);
+
+        let hasher = TestHasher;
+        for depth in 1..=tree.tree_depth as u32 {
+            // Fold the roots of the current layer up to the tree root.
+            let mut layer: Vec<_> = (0..1 << depth)
+                .map(|idx| tree.subtree_root(idx, depth))
+                .collect();
+            for cur_depth in (0..depth as usize).rev() {
+                layer = layer
+                    .chunks(2)
+                    .map(|pair| {
+                        hasher.compress(&pair[0], &pair[1], tree.calculate_level(cur_depth))
+                    })
+                    .collect();
+            }
+            assert_eq!(layer, vec![root_hash]);
+        }
+
+        // Subtree without any items has the default hash.
+        assert_eq!(tree.subtree_root(1, 2), tree.prehashed[2]);
+        assert_eq!(
+            tree.subtree_root(1, 4),
+            hasher.hash_bits(TestLeaf(0).get_bits_le())
+        );
+    }
+
+    /// Checks that the empty tree has the known root and returns to it once all the items are removed.
+    #[test]
+    fn empty_root_test() {
+        for depth in 2..6 {
+            let mut tree = TestSMT::new(depth);
+            let empty_root = TestSMT::empty_root(depth);
+            assert_eq!(tree.root_hash(), empty_root);
+
+            for idx in 0..tree.capacity() as u32 {
+                tree.insert(idx, TestLeaf(idx as u64 + 1));
+            }
+            assert_ne!(tree.root_hash(), empty_root);
+
+            for idx in 0..tree.capacity() as u32 {
+                tree.remove(idx);
+            }
+            assert_eq!(tree.root_hash(), empty_root);
+        }
+    }
+
+    /// Checks that ordered iteration doesn't depend on the insertion order.
+    #[test]
+    fn iter_ordered_test() {
+        let indices = [5, 0, 7, 3, 1];
+
+        let mut tree = TestSMT::new(3);
+        for &idx in &indices {
+            tree.insert(idx, TestLeaf(idx as u64));
+        }
+        let mut reversed_tree = TestSMT::new(3);
+        for &idx in indices.iter().rev() {
+            reversed_tree.insert(idx, TestLeaf(idx as u64));
+        }
+
+        let items: Vec<_> = tree.iter_ordered().collect();
+        assert_eq!(items, reversed_tree.iter_ordered().collect::<Vec<_>>());
+        assert_eq!(
+            items.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+            vec![0, 1, 3, 5, 7]
+        );
+        assert_eq!(tree.root_hash(), reversed_tree.root_hash());
+    }
 }
@@ -1,5 +1,7 @@
 use crate::handler::error::*;
 use thiserror::Error;
+use zksync_crypto::params::BALANCE_BIT_WIDTH;
+use zksync_types::{AccountId, Address, TokenId};
 
 #[derive(Clone, Debug, Error, PartialEq)]
 pub enum OpError {
@@ -31,3 +33,20 @@ pub struct TxBatchError {
     pub failed_tx_index: usize,
     pub reason: OpError,
 }
+
+/// Inconsistency found in the state restored from an external source.
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum IntegrityError {
+    #[error("Account id {0} is out of the range supported by the circuit")]
+    AccountIdOutOfRange(AccountId),
+    #[error("Balance of token {token} of account {account_id} does not fit into {BALANCE_BIT_WIDTH} bits")]
+    BalanceOverflow {
+        account_id: AccountId,
+        token: TokenId,
+    },
+    #[error("Address {address:?} of account {account_id} is not indexed")]
+    AddressNotIndexed {
+        account_id: AccountId,
+        address: Address,
+    },
+}
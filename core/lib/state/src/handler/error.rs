@@ -90,6 +90,8 @@ pub enum TransferOpError {
     NonceMismatch,
     #[error("Not enough balance")]
     InsufficientBalance,
+    #[error("Target account balance overflow")]
+    TargetBalanceOverflow,
     #[error("Bug: transfer to self should not be called")]
     CannotTransferToSelf,
 }
@@ -201,4 +203,6 @@ pub enum SwapOpError {
     AmountsNotCompatible,
     #[error("Self-swap is not allowed")]
     SelfSwap,
+    #[error("Recipient account balance overflow")]
+    RecipientBalanceOverflow,
 }
@@ -1,7 +1,7 @@
 use num::{BigUint, Zero};
 use std::time::Instant;
 use zksync_crypto::params::{max_account_id, max_processable_token, max_token_id};
-use zksync_types::{AccountUpdates, Order, PubKeyHash, Swap, SwapOp};
+use zksync_types::{AccountId, AccountUpdates, Order, PubKeyHash, Swap, SwapOp, TokenId};
 
 use crate::handler::error::SwapOpError;
 use crate::{
@@ -144,7 +144,20 @@ impl ZkSyncState {
         };
 
         verify_account(&tx.orders.0, &tx.amounts.0)?;
-        verify_account(&tx.orders.1, &tx.amounts.1)
+        verify_account(&tx.orders.1, &tx.amounts.1)?;
+
+        // Accounts are only updated once all the checks have passed, so we check the credits on copies.
+        let verify_recipient = |recipient_id: AccountId, token: TokenId, amount: &BigUint| {
+            let mut recipient = self.get_account(recipient_id).unwrap();
+            invariant!(
+                recipient.checked_add_balance(token, amount),
+                SwapOpError::RecipientBalanceOverflow
+            );
+            Ok(())
+        };
+
+        verify_recipient(op.recipients.1, tx.orders.0.token_sell, &tx.amounts.0)?;
+        verify_recipient(op.recipients.0, tx.orders.1.token_sell, &tx.amounts.1)
     }
 
     fn verify_swap(&self, swap: &Swap) -> Result<(), SwapOpError> {
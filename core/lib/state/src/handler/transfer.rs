@@ -130,7 +130,10 @@ impl ZkSyncState {
         let to_old_balance = to_account.get_balance(op.tx.token);
         let to_account_nonce = to_account.nonce;
 
-        to_account.add_balance(op.tx.token, &op.tx.amount);
+        invariant!(
+            to_account.checked_add_balance(op.tx.token, &op.tx.amount),
+            TransferOpError::TargetBalanceOverflow
+        );
 
         let to_new_balance = to_account.get_balance(op.tx.token);
 
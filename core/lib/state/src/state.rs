@@ -1,7 +1,13 @@
 use num::BigUint;
+use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
 
-use zksync_crypto::{merkle_tree::TreeMemoryUsage, params, params::NFT_STORAGE_ACCOUNT_ID, Fr};
+use zksync_crypto::{
+    merkle_tree::TreeMemoryUsage,
+    params,
+    params::{BALANCE_BIT_WIDTH, NFT_STORAGE_ACCOUNT_ID},
+    Fr,
+};
 use zksync_types::{
     helpers::reverse_updates,
     operations::{TransferOp, TransferToNewOp, ZkSyncOp},
@@ -10,7 +16,7 @@ use zksync_types::{
 };
 
 use crate::{
-    error::{OpError, TxBatchError},
+    error::{IntegrityError, OpError, TxBatchError},
     handler::{error::CloseOpError, TxHandler},
     tx_ext::TxCheck,
 };
@@ -62,10 +68,19 @@ impl TransferOutcome {
     }
 }
 
+/// Root hash of the account tree without any accounts.
+static EMPTY_TREE_ROOT: Lazy<Fr> =
+    Lazy::new(|| AccountTree::empty_root(params::account_tree_depth()));
+
 impl ZkSyncState {
     pub fn empty() -> Self {
         let tree_depth = params::account_tree_depth();
         let balance_tree = AccountTree::new(tree_depth);
+        debug_assert_eq!(
+            balance_tree.root_hash(),
+            Self::empty_root(),
+            "Newly created account tree doesn't have the empty tree root"
+        );
         Self {
             balance_tree,
             account_id_by_address: HashMap::new(),
@@ -74,6 +89,12 @@ impl ZkSyncState {
         }
     }
 
+    /// Returns the root hash of the account tree without any accounts.
+    /// It only depends on the tree depth, so it's calculated once.
+    pub fn empty_root() -> Fr {
+        *EMPTY_TREE_ROOT
+    }
+
     pub fn from_acc_map(accounts: AccountMap) -> Self {
         let mut empty = Self::empty();
 
@@ -111,24 +132,71 @@ impl ZkSyncState {
         }
     }
 
+    /// Checks the internal consistency of the state, e.g. after it was restored from external data.
+    /// Root hash comparison alone can't catch accounts the circuit isn't able to process, so every
+    /// leaf is checked to have an id and balances within the circuit limits and to be indexed by its address.
+    ///
+    /// Returns the first inconsistency found.
+    pub fn validate_integrity(&self) -> Result<(), IntegrityError> {
+        for (id, account) in self.balance_tree.iter_ordered() {
+            if account == &Account::default() {
+                continue;
+            }
+
+            let account_id = AccountId(id);
+            if account_id > params::max_account_id() && account_id != NFT_STORAGE_ACCOUNT_ID {
+                return Err(IntegrityError::AccountIdOutOfRange(account_id));
+            }
+
+            let mut balances: Vec<_> = account.get_nonzero_balances().into_iter().collect();
+            balances.sort_unstable_by_key(|(token, _)| *token);
+            for (token, balance) in balances {
+                if balance.0.bits() as usize > BALANCE_BIT_WIDTH {
+                    return Err(IntegrityError::BalanceOverflow { account_id, token });
+                }
+            }
+
+            if self.account_id_by_address.get(&account.address) != Some(&account_id) {
+                return Err(IntegrityError::AddressNotIndexed {
+                    account_id,
+                    address: account.address,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn tree_memory_stats(&self) -> TreeMemoryUsage {
         self.balance_tree.memory_stats()
     }
 
+    /// Returns all the non-empty accounts ordered by their ids.
     pub fn get_accounts(&self) -> Vec<(u32, Account)> {
         self.balance_tree
-            .items
-            .iter()
-            .filter_map(|a| {
-                if a.1 == &Account::default() {
+            .iter_ordered()
+            .filter_map(|(id, account)| {
+                if account == &Account::default() {
                     None
                 } else {
-                    Some((*a.0 as u32, a.1.clone()))
+                    Some((id, account.clone()))
                 }
             })
             .collect()
     }
 
+    /// Returns the sum of balances of the given token across all the accounts.
+    ///
+    /// Fees collected in the pending block are not included until `collect_fee` is called.
+    /// Goes through every account in the tree, so it's meant for invariant checks only.
+    pub fn total_supply(&self, token: TokenId) -> BigUint {
+        self.balance_tree
+            .items
+            .values()
+            .map(|account| account.get_balance(token))
+            .sum()
+    }
+
     pub fn root_hash(&self) -> Fr {
         let start = std::time::Instant::now();
         let hash = self.balance_tree.root_hash();
@@ -136,6 +204,12 @@ impl ZkSyncState {
         hash
     }
 
+    /// Returns the root hash of the subtree that covers `2^(tree_depth - prefix_depth)` accounts,
+    /// starting from the account with id `prefix_index << (tree_depth - prefix_depth)`.
+    pub fn subtree_root(&self, prefix_index: u32, prefix_depth: u32) -> Fr {
+        self.balance_tree.subtree_root(prefix_index, prefix_depth)
+    }
+
     pub fn get_account(&self, account_id: AccountId) -> Option<Account> {
         let start = std::time::Instant::now();
         let account = self
@@ -368,7 +442,12 @@ impl ZkSyncState {
 
             let old_amount = account.get_balance(fee.token).clone();
             let nonce = account.nonce;
-            account.add_balance(fee.token, &fee.amount);
+            // Fees are already charged at this point, so the overflow can't be rejected anymore.
+            assert!(
+                account.checked_add_balance(fee.token, &fee.amount),
+                "Fee account balance overflow for token {}",
+                *fee.token
+            );
             let new_amount = account.get_balance(fee.token).clone();
 
             updates.push((
@@ -1149,4 +1228,110 @@ mod tests {
         let plasma_state = ZkSyncState::from_acc_map(account_map);
         assert_eq!(*plasma_state.next_free_id, 6);
     }
+
+    /// Checks that changing an account only changes the roots of the subtrees containing it.
+    #[test]
+    fn subtree_root() {
+        let mut account_map = AccountMap::default();
+        account_map.insert(
+            AccountId(0),
+            Account::default_with_address(&Address::random()),
+        );
+        let mut state = ZkSyncState::from_acc_map(account_map);
+        assert_eq!(state.subtree_root(0, 0), state.root_hash());
+
+        // Subtrees at this depth cover pairs of accounts: (0, 1), (2, 3) and so on.
+        let depth = params::account_tree_depth() as u32 - 1;
+        let first_pair_root = state.subtree_root(0, depth);
+        let second_pair_root = state.subtree_root(1, depth);
+
+        state.insert_account(
+            AccountId(2),
+            Account::default_with_address(&Address::random()),
+        );
+        assert_eq!(state.subtree_root(0, 0), state.root_hash());
+        assert_eq!(state.subtree_root(0, depth), first_pair_root);
+        assert_ne!(state.subtree_root(1, depth), second_pair_root);
+    }
+
+    /// Checks that `empty_root` is the root hash of a state without accounts.
+    #[test]
+    fn empty_root() {
+        let mut state = ZkSyncState::empty();
+        assert_eq!(state.root_hash(), ZkSyncState::empty_root());
+
+        state.insert_account(
+            AccountId(0),
+            Account::default_with_address(&Address::random()),
+        );
+        assert_ne!(state.root_hash(), ZkSyncState::empty_root());
+    }
+
+    /// Checks that `validate_integrity` accepts a consistent state.
+    #[test]
+    fn validate_integrity_ok() {
+        let mut account = Account::default_with_address(&Address::random());
+        account.set_balance(TokenId(0), BigUint::from(u128::MAX));
+        let mut account_map = AccountMap::default();
+        account_map.insert(AccountId(0), account);
+        account_map.insert(
+            NFT_STORAGE_ACCOUNT_ID,
+            Account::default_with_address(&Address::random()),
+        );
+
+        let state = ZkSyncState::from_acc_map(account_map);
+        assert_eq!(state.validate_integrity(), Ok(()));
+    }
+
+    /// Checks that `validate_integrity` detects a balance wider than the circuit supports.
+    #[test]
+    fn validate_integrity_balance_overflow() {
+        let mut account = Account::default_with_address(&Address::random());
+        account.set_balance(TokenId(1), BigUint::from(u128::MAX) + 1u32);
+        let mut account_map = AccountMap::default();
+        account_map.insert(AccountId(0), account);
+
+        let state = ZkSyncState::from_acc_map(account_map);
+        assert_eq!(
+            state.validate_integrity(),
+            Err(IntegrityError::BalanceOverflow {
+                account_id: AccountId(0),
+                token: TokenId(1),
+            })
+        );
+    }
+
+    /// Checks that `validate_integrity` detects an account id outside of the supported subtree.
+    #[test]
+    fn validate_integrity_account_id_out_of_range() {
+        let account_id = NFT_STORAGE_ACCOUNT_ID + 1;
+        let mut account_map = AccountMap::default();
+        account_map.insert(
+            account_id,
+            Account::default_with_address(&Address::random()),
+        );
+
+        let state = ZkSyncState::from_acc_map(account_map);
+        assert_eq!(
+            state.validate_integrity(),
+            Err(IntegrityError::AccountIdOutOfRange(account_id))
+        );
+    }
+
+    /// Checks that `validate_integrity` detects an account missing from the address index.
+    #[test]
+    fn validate_integrity_address_not_indexed() {
+        let address = Address::random();
+        let mut balance_tree = AccountTree::new(params::account_tree_depth());
+        balance_tree.insert(0, Account::default_with_address(&address));
+
+        let state = ZkSyncState::new(balance_tree, HashMap::new(), HashMap::new());
+        assert_eq!(
+            state.validate_integrity(),
+            Err(IntegrityError::AddressNotIndexed {
+                account_id: AccountId(0),
+                address,
+            })
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use super::{AccountState::*, PlasmaTestBuilder};
 use crate::state::CollectedFee;
 use num::{BigUint, Zero};
-use zksync_types::{account::AccountUpdate, AccountId, TokenId};
+use zksync_types::{account::AccountUpdate, AccountId, TokenId, Transfer};
 
 /// Checks if fees are collected correctly.
 /// Fees are not only in ETH and may be zero.
@@ -61,3 +61,59 @@ fn invalid_account() {
     let mut tb = PlasmaTestBuilder::new();
     tb.state.collect_fee(&[], AccountId(145));
 }
+
+/// Checks that collect_fee panics if the fee account balance would overflow.
+#[test]
+#[should_panic(expected = "Fee account balance overflow for token 0")]
+fn balance_overflow() {
+    let mut tb = PlasmaTestBuilder::new();
+    let (account_id, _, _) = tb.add_account(Locked);
+    tb.set_balance(account_id, TokenId(0), BigUint::from(u128::MAX));
+
+    tb.state.collect_fee(
+        &[CollectedFee {
+            token: TokenId(0),
+            amount: BigUint::from(1u32),
+        }],
+        account_id,
+    );
+}
+
+/// Checks that the total supply of a token is preserved once the fees are collected.
+#[test]
+fn total_supply_preserved() {
+    let token_id = TokenId(0);
+    let amount = BigUint::from(100u32);
+    let fee = BigUint::from(10u32);
+
+    let mut tb = PlasmaTestBuilder::new();
+    let (fee_account_id, _, _) = tb.add_account(Locked);
+    let (from_account_id, from_account, from_sk) = tb.add_account(Unlocked);
+    tb.set_balance(from_account_id, token_id, 200u32);
+    let (_, to_account, _) = tb.add_account(Locked);
+
+    let initial_supply = tb.state.total_supply(token_id);
+    assert_eq!(initial_supply, BigUint::from(200u32));
+
+    let transfer = Transfer::new_signed(
+        from_account_id,
+        from_account.address,
+        to_account.address,
+        token_id,
+        amount,
+        fee.clone(),
+        from_account.nonce,
+        Default::default(),
+        &from_sk,
+    )
+    .unwrap();
+    let op_success = tb
+        .state
+        .execute_tx(transfer.into(), tb.block_timestamp)
+        .expect("transaction failed");
+    assert_eq!(tb.state.total_supply(token_id), &initial_supply - &fee);
+
+    let collected_fee = op_success.fee.expect("transfer has a fee");
+    tb.state.collect_fee(&[collected_fee], fee_account_id);
+    assert_eq!(tb.state.total_supply(token_id), initial_supply);
+}
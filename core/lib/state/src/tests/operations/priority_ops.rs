@@ -73,6 +73,56 @@ fn deposit_to_new() {
     )
 }
 
+/// Check that two deposits to the same account within one block are applied sequentially:
+/// the second deposit's update starts from the balance left by the first one, so reverting
+/// the block updates in reverse order restores the original state.
+#[test]
+fn two_deposits_to_same_account() {
+    let token = TokenId(0);
+    let mut tb = PlasmaTestBuilder::new();
+    let address = H160::random();
+    let account_id = tb.state.get_free_account_id();
+    let initial_root_hash = tb.state.root_hash();
+
+    let mut block_updates = Vec::new();
+    for amount in &[100u32, 50u32] {
+        let deposit = Deposit {
+            from: address,
+            to: address,
+            amount: BigUint::from(*amount),
+            token,
+        };
+        let mut op_success = tb
+            .state
+            .execute_priority_op(ZkSyncPriorityOp::Deposit(deposit));
+        block_updates.append(&mut op_success.updates);
+    }
+
+    let account = tb.state.get_account(account_id).unwrap();
+    assert_eq!(account.get_balance(token), BigUint::from(150u32));
+    assert_eq!(
+        block_updates.last().unwrap(),
+        &(
+            account_id,
+            AccountUpdate::UpdateBalance {
+                old_nonce: Nonce(0),
+                new_nonce: Nonce(0),
+                balance_update: (token, BigUint::from(100u32), BigUint::from(150u32)),
+            },
+        )
+    );
+
+    let reverted_updates = block_updates
+        .iter()
+        .rev()
+        .map(|(id, update)| (*id, update.reversed_update()))
+        .collect();
+    tb.state.apply_account_updates(reverted_updates);
+
+    assert!(tb.state.get_account(account_id).is_none());
+    assert_eq!(tb.state.root_hash(), initial_root_hash);
+}
+
 /// Check failure of FullExit operation for non-existent account
 #[test]
 fn full_exit_non_existent() {
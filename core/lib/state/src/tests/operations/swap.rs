@@ -313,6 +313,38 @@ fn not_enough_balance() {
     test_swap.test(tb, Failure("Not enough balance"));
 }
 
+/// Accounts try to swap, one of the recipients can't receive the whole amount, should fail
+#[test]
+fn recipient_balance_overflow() {
+    let mut tb = PlasmaTestBuilder::new();
+
+    let test_swap = TestSwap {
+        accounts: (0, 1),
+        recipients: (2, 3),
+        submitter: 4,
+        tokens: (18, 19),
+        fee_token: 0,
+        amounts: (50, 100),
+        fee: 25,
+        balances: (100, 200, 50),
+        first_price: (1, 2),
+        second_price: (2, 1),
+        is_limit_order: (false, false),
+        test_accounts: vec![
+            tb.add_account(Unlocked),
+            tb.add_account(Unlocked),
+            tb.add_account(Unlocked),
+            tb.add_account(Unlocked),
+            tb.add_account(Unlocked),
+        ],
+    };
+
+    let (recipient_1_id, _, _) = &test_swap.test_accounts[test_swap.recipients.1];
+    tb.set_balance(*recipient_1_id, TokenId(18), BigUint::from(u128::MAX));
+
+    test_swap.test(tb, Failure("Recipient account balance overflow"));
+}
+
 /// Prices in orders are not compatible with amounts, should fail
 #[test]
 fn wrong_prices() {
@@ -84,6 +84,139 @@ fn insufficient_funds() {
     tb.test_tx_fail(transfer.into(), "Not enough balance");
 }
 
+/// Check Transfer failure if the target balance would not fit into the circuit
+#[test]
+fn target_balance_overflow() {
+    let token_id = TokenId(0);
+    let amount = BigUint::from(100u32);
+    let fee = BigUint::from(10u32);
+
+    let mut tb = PlasmaTestBuilder::new();
+
+    let (from_account_id, from_account, from_sk) = tb.add_account(Unlocked);
+    tb.set_balance(from_account_id, token_id, &amount + &fee);
+
+    let (to_account_id, to_account, _to_sk) = tb.add_account(Locked);
+    tb.set_balance(to_account_id, token_id, BigUint::from(u128::MAX) - 99u32);
+
+    let transfer = Transfer::new_signed(
+        from_account_id,
+        from_account.address,
+        to_account.address,
+        token_id,
+        amount,
+        fee,
+        from_account.nonce,
+        Default::default(),
+        &from_sk,
+    )
+    .unwrap();
+
+    tb.test_tx_fail(transfer.into(), "Target account balance overflow");
+}
+
+/// Check Transfer operation which sets the target balance to the max supported value
+#[test]
+fn target_balance_max() {
+    let token_id = TokenId(0);
+    let amount = BigUint::from(100u32);
+    let fee = BigUint::from(10u32);
+    let to_balance = BigUint::from(u128::MAX) - &amount;
+
+    let mut tb = PlasmaTestBuilder::new();
+
+    let (from_account_id, from_account, from_sk) = tb.add_account(Unlocked);
+    tb.set_balance(from_account_id, token_id, &amount + &fee);
+
+    let (to_account_id, to_account, _to_sk) = tb.add_account(Locked);
+    tb.set_balance(to_account_id, token_id, to_balance.clone());
+
+    let transfer = Transfer::new_signed(
+        from_account_id,
+        from_account.address,
+        to_account.address,
+        token_id,
+        amount.clone(),
+        fee.clone(),
+        from_account.nonce,
+        Default::default(),
+        &from_sk,
+    )
+    .unwrap();
+
+    tb.test_tx_success(
+        transfer.into(),
+        &[
+            (
+                from_account_id,
+                AccountUpdate::UpdateBalance {
+                    old_nonce: from_account.nonce,
+                    new_nonce: from_account.nonce + 1,
+                    balance_update: (token_id, &amount + &fee, BigUint::zero()),
+                },
+            ),
+            (
+                to_account_id,
+                AccountUpdate::UpdateBalance {
+                    old_nonce: to_account.nonce,
+                    new_nonce: to_account.nonce,
+                    balance_update: (token_id, to_balance, BigUint::from(u128::MAX)),
+                },
+            ),
+        ],
+    )
+}
+
+/// Check Transfer operation with zero amount, which is valid even if the target balance is at the max
+#[test]
+fn zero_amount() {
+    let token_id = TokenId(0);
+    let fee = BigUint::from(10u32);
+
+    let mut tb = PlasmaTestBuilder::new();
+
+    let (from_account_id, from_account, from_sk) = tb.add_account(Unlocked);
+    tb.set_balance(from_account_id, token_id, fee.clone());
+
+    let (to_account_id, to_account, _to_sk) = tb.add_account(Locked);
+    tb.set_balance(to_account_id, token_id, BigUint::from(u128::MAX));
+
+    let transfer = Transfer::new_signed(
+        from_account_id,
+        from_account.address,
+        to_account.address,
+        token_id,
+        BigUint::zero(),
+        fee.clone(),
+        from_account.nonce,
+        Default::default(),
+        &from_sk,
+    )
+    .unwrap();
+
+    tb.test_tx_success(
+        transfer.into(),
+        &[
+            (
+                from_account_id,
+                AccountUpdate::UpdateBalance {
+                    old_nonce: from_account.nonce,
+                    new_nonce: from_account.nonce + 1,
+                    balance_update: (token_id, fee, BigUint::zero()),
+                },
+            ),
+            (
+                to_account_id,
+                AccountUpdate::UpdateBalance {
+                    old_nonce: to_account.nonce,
+                    new_nonce: to_account.nonce,
+                    balance_update: (token_id, BigUint::from(u128::MAX), BigUint::from(u128::MAX)),
+                },
+            ),
+        ],
+    )
+}
+
 /// Check Transfer operation to new account
 #[test]
 fn to_new() {
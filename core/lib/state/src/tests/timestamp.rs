@@ -1,9 +1,9 @@
 use chrono::Utc;
-use num::BigUint;
+use num::{BigUint, Zero};
 use zksync_types::{
     account::PubKeyHash,
     tx::{ChangePubKey, TimeRange, Withdraw},
-    SignedZkSyncTx, TokenId, ZkSyncTx,
+    AccountUpdate, SignedZkSyncTx, TokenId, ZkSyncTx,
 };
 
 use crate::tests::{AccountState::*, PlasmaTestBuilder};
@@ -75,6 +75,91 @@ fn invalid_timestamp_valid_until() {
     );
 }
 
+/// Check that transaction is accepted in the block with timestamp equal to `valid_until`
+#[test]
+fn valid_until_is_inclusive() {
+    const CURRENT_TIMESTAMP: u64 = 100;
+
+    let token_id = TokenId(0);
+    let amount = BigUint::from(100u32);
+    let fee = BigUint::from(10u32);
+
+    let mut tb = PlasmaTestBuilder::new();
+    let (account_id, account, sk) = tb.add_account(Unlocked);
+    tb.set_balance(account_id, token_id, &amount + &fee);
+
+    tb.set_timestamp(CURRENT_TIMESTAMP);
+
+    let time_range = TimeRange {
+        valid_until: CURRENT_TIMESTAMP,
+        ..Default::default()
+    };
+
+    let withdraw = Withdraw::new_signed(
+        account_id,
+        account.address,
+        account.address,
+        token_id,
+        amount.clone(),
+        fee.clone(),
+        account.nonce,
+        time_range,
+        &sk,
+    )
+    .unwrap();
+
+    tb.test_tx_success(
+        withdraw.into(),
+        &[(
+            account_id,
+            AccountUpdate::UpdateBalance {
+                old_nonce: account.nonce,
+                new_nonce: account.nonce + 1,
+                balance_update: (token_id, &amount + &fee, BigUint::zero()),
+            },
+        )],
+    )
+}
+
+/// Check that the timestamp is checked before the nonce
+#[test]
+fn timestamp_checked_before_nonce() {
+    const CURRENT_TIMESTAMP: u64 = 100;
+
+    let token_id = TokenId(0);
+    let amount = BigUint::from(100u32);
+    let fee = BigUint::from(10u32);
+
+    let mut tb = PlasmaTestBuilder::new();
+    let (account_id, account, sk) = tb.add_account(Unlocked);
+    tb.set_balance(account_id, token_id, &amount + &fee);
+
+    tb.set_timestamp(CURRENT_TIMESTAMP);
+
+    let time_range = TimeRange {
+        valid_until: CURRENT_TIMESTAMP - 1,
+        ..Default::default()
+    };
+
+    let withdraw = Withdraw::new_signed(
+        account_id,
+        account.address,
+        account.address,
+        token_id,
+        amount,
+        fee,
+        account.nonce + 1,
+        time_range,
+        &sk,
+    )
+    .unwrap();
+
+    tb.test_tx_fail(
+        withdraw.into(),
+        "The transaction can't be executed in the block because of an invalid timestamp",
+    );
+}
+
 /// Check that batch fails if timestamp is invalid
 #[test]
 fn batch_invalid_timestamp() {
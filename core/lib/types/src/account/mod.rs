@@ -10,6 +10,7 @@ use zksync_crypto::{
         utils::eth_address_to_fr,
     },
     franklin_crypto::bellman::pairing::ff::PrimeField,
+    params::BALANCE_BIT_WIDTH,
     primitives::GetBits,
 };
 use zksync_utils::BigUintSerdeWrapper;
@@ -165,6 +166,20 @@ impl Account {
         self.balances.insert(token, balance);
     }
 
+    /// Adds the provided amount to the token balance if the resulting balance fits into
+    /// `BALANCE_BIT_WIDTH` bits, which is the widest balance supported by the circuit.
+    ///
+    /// Returns `false` and leaves the balance unchanged otherwise.
+    #[must_use]
+    pub fn checked_add_balance(&mut self, token: TokenId, amount: &BigUint) -> bool {
+        let new_balance = self.get_balance(token) + amount;
+        if new_balance.bits() as usize > BALANCE_BIT_WIDTH {
+            return false;
+        }
+        self.set_balance(token, new_balance);
+        true
+    }
+
     /// Subtracts the provided amount from the token balance.
     ///
     /// # Panics
@@ -306,6 +321,24 @@ mod test {
         assert!(!account.is_default(), "Account has non-zero balance");
     }
 
+    #[test]
+    fn checked_add_balance() {
+        let max_balance = (BigUint::from(1u32) << BALANCE_BIT_WIDTH) - 1u32;
+
+        let mut account = Account::default();
+        assert!(account.checked_add_balance(TokenId(0), &(&max_balance - 1u32)));
+        assert!(account.checked_add_balance(TokenId(0), &1u32.into()));
+        assert_eq!(account.get_balance(TokenId(0)), max_balance);
+
+        // Overflowing credit doesn't change the balance.
+        assert!(!account.checked_add_balance(TokenId(0), &1u32.into()));
+        assert_eq!(account.get_balance(TokenId(0)), max_balance);
+
+        // Balances of other tokens are independent.
+        assert!(account.checked_add_balance(TokenId(1), &1u32.into()));
+        assert_eq!(account.get_balance(TokenId(1)), BigUint::from(1u32));
+    }
+
     #[test]
     fn is_deeply_equal_account() {
         // Default account equals default account.
@@ -452,6 +452,25 @@ fn test_musig_rescue_signing_verification() {
     }
 }
 
+/// Checks that signing the same message with the same key always produces the same signature,
+/// since the nonce is derived from the key and the message rather than from an RNG.
+#[test]
+fn test_musig_rescue_signing_is_deterministic() {
+    let (pk, messages) = gen_pk_and_msg();
+
+    for msg in &messages {
+        let first_signature = TxSignature::sign_musig_rescue(&pk, msg);
+        let second_signature = TxSignature::sign_musig_rescue(&pk, msg);
+
+        assert_eq!(
+            first_signature.signature.serialize_packed().unwrap(),
+            second_signature.signature.serialize_packed().unwrap(),
+            "Signatures differ, msg: {}",
+            hex::encode(msg)
+        );
+    }
+}
+
 #[test]
 fn test_ethereum_signature_verify_with_serialization() {
     let address: Address = "52312AD6f01657413b2eaE9287f6B9ADaD93D5FE".parse().unwrap();
@@ -3,6 +3,7 @@ use futures::{
     SinkExt,
 };
 use std::thread::JoinHandle;
+use std::time::Duration;
 use tokio::runtime::Runtime;
 use zksync_core::{
     committer::CommitRequest,
@@ -19,6 +20,9 @@ use zksync_types::{
 use itertools::Itertools;
 use zksync_mempool::MempoolBlocksRequest;
 
+/// Time the state keeper waits for the events emitter to accept processed operations.
+const EVENTS_SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub async fn state_keeper_get_account(
     mut sender: mpsc::Sender<StateKeeperTestkitRequest>,
     address: &Address,
@@ -72,6 +76,7 @@ pub fn spawn_state_keeper(
         block_chunks_sizes,
         max_miniblock_iterations,
         max_miniblock_iterations,
+        EVENTS_SEND_TIMEOUT,
         processed_tx_events_sender,
     );
 